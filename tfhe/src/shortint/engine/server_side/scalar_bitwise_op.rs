@@ -42,9 +42,53 @@ impl ShortintEngine {
         ct_left: &mut Ciphertext,
         ct_right: u8,
     ) {
+        // The lookup table reduces its input modulo the message modulus and outputs a ciphertext
+        // with an empty carry, so no refresh is needed before applying it. The same holds for the
+        // other smart scalar bitwise operations.
         self.unchecked_scalar_bitand_assign(server_key, ct_left, ct_right);
     }
 
+    pub(crate) fn unchecked_scalar_bitand_u16(
+        &mut self,
+        server_key: &ServerKey,
+        lhs: &Ciphertext,
+        rhs: u16,
+    ) -> Ciphertext {
+        let mut result = lhs.clone();
+        self.unchecked_scalar_bitand_u16_assign(server_key, &mut result, rhs);
+        result
+    }
+
+    pub(crate) fn unchecked_scalar_bitand_u16_assign(
+        &mut self,
+        server_key: &ServerKey,
+        lhs: &mut Ciphertext,
+        rhs: u16,
+    ) {
+        let lut = server_key.generate_msg_lookup_table(|x| x & rhs as u64, lhs.message_modulus);
+        self.apply_lookup_table_assign(server_key, lhs, &lut);
+    }
+
+    pub(crate) fn unchecked_scalar_bitnot(
+        &mut self,
+        server_key: &ServerKey,
+        lhs: &Ciphertext,
+    ) -> Ciphertext {
+        let mut result = lhs.clone();
+        self.unchecked_scalar_bitnot_assign(server_key, &mut result);
+        result
+    }
+
+    pub(crate) fn unchecked_scalar_bitnot_assign(
+        &mut self,
+        server_key: &ServerKey,
+        lhs: &mut Ciphertext,
+    ) {
+        let mask = lhs.message_modulus.0 as u64 - 1;
+        let lut = server_key.generate_msg_lookup_table(|x| (!x) & mask, lhs.message_modulus);
+        self.apply_lookup_table_assign(server_key, lhs, &lut);
+    }
+
     pub(crate) fn unchecked_scalar_bitxor(
         &mut self,
         server_key: &ServerKey,
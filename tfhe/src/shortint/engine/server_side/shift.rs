@@ -20,6 +20,9 @@ impl ShortintEngine {
         ct: &mut Ciphertext,
         shift: u8,
     ) {
+        // Shifting by the message bit-width or more always gives 0, clamping avoids overflowing
+        // the shift in the lookup table
+        let shift = shift.min(ct.message_modulus.0.ilog2() as u8);
         let acc = server_key.generate_msg_lookup_table(|x| x >> shift, ct.message_modulus);
         self.apply_lookup_table_assign(server_key, ct, &acc);
 
@@ -56,9 +59,14 @@ impl ShortintEngine {
         ct: &mut Ciphertext,
         shift: u8,
     ) {
-        if server_key.is_scalar_left_shift_possible(ct, shift).is_ok() {
+        // Shifts as wide as the whole carry-message space never fit in the carry, they go through
+        // the lookup table which is the only path able to handle them without overflowing
+        let full_bits = (server_key.message_modulus.0 * server_key.carry_modulus.0).ilog2() as u8;
+        if shift < full_bits && server_key.is_scalar_left_shift_possible(ct, shift).is_ok() {
             self.unchecked_scalar_left_shift_assign(ct, shift);
         } else {
+            // Shifting by the message bit-width or more always gives 0 in the message space
+            let shift = shift.min(server_key.message_modulus.0.ilog2() as u8);
             let modulus = server_key.message_modulus.0 as u64;
             let acc =
                 server_key.generate_msg_lookup_table(|x| x << shift, server_key.message_modulus);
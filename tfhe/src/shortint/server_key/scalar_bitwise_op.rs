@@ -64,6 +64,80 @@ impl ServerKey {
         })
     }
 
+    /// Compute homomorphically a bitwise AND between a ciphertext and a clear `u16` value
+    ///
+    /// Bits of `rhs` above the message space have no effect on the result.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let msg1 = 3u64;
+    /// let msg2 = 0x00FFu16;
+    ///
+    /// // Encrypt a message:
+    /// let ct1 = cks.encrypt(msg1);
+    ///
+    /// // Compute homomorphically an AND:
+    /// let ct_res = sks.unchecked_scalar_bitand_u16(&ct1, msg2);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(msg1 & msg2 as u64, res);
+    /// ```
+    pub fn unchecked_scalar_bitand_u16(&self, lhs: &Ciphertext, rhs: u16) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.unchecked_scalar_bitand_u16(self, lhs, rhs)
+        })
+    }
+
+    pub fn unchecked_scalar_bitand_u16_assign(&self, lhs: &mut Ciphertext, rhs: u16) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.unchecked_scalar_bitand_u16_assign(self, lhs, rhs)
+        })
+    }
+
+    /// Compute homomorphically the bitwise complement of a ciphertext
+    ///
+    /// Only the message bits are complemented, the result lies in the message space.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let msg = 2u64;
+    /// let modulus = cks.parameters.message_modulus().0 as u64;
+    ///
+    /// // Encrypt a message:
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically a NOT:
+    /// let ct_res = sks.unchecked_scalar_bitnot(&ct);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(!msg % modulus, res);
+    /// ```
+    pub fn unchecked_scalar_bitnot(&self, lhs: &Ciphertext) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| engine.unchecked_scalar_bitnot(self, lhs))
+    }
+
+    pub fn unchecked_scalar_bitnot_assign(&self, lhs: &mut Ciphertext) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.unchecked_scalar_bitnot_assign(self, lhs)
+        })
+    }
+
     /// Compute homomorphically a bitwise XOR between a ciphertext and a clear value
     ///
     ///
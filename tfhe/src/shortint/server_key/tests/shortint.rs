@@ -197,6 +197,7 @@ create_parametrized_test!(shortint_unchecked_right_shift);
 create_parametrized_test!(shortint_default_right_shift);
 create_parametrized_test!(shortint_unchecked_left_shift);
 create_parametrized_test!(shortint_default_left_shift);
+create_parametrized_test!(shortint_lookup_table_shift_clamped);
 create_parametrized_test!(shortint_unchecked_sub);
 create_parametrized_test!(shortint_smart_sub);
 create_parametrized_test!(shortint_default_sub);
@@ -205,9 +206,12 @@ create_parametrized_test!(shortint_mux);
 create_parametrized_test!(shortint_unchecked_scalar_bitand);
 create_parametrized_test!(shortint_unchecked_scalar_bitor);
 create_parametrized_test!(shortint_unchecked_scalar_bitxor);
+create_parametrized_test!(shortint_unchecked_scalar_bitnot);
+create_parametrized_test!(shortint_unchecked_scalar_bitand_u16);
 create_parametrized_test!(shortint_smart_scalar_bitand);
 create_parametrized_test!(shortint_smart_scalar_bitor);
 create_parametrized_test!(shortint_smart_scalar_bitxor);
+create_parametrized_test!(shortint_smart_scalar_bitwise_chain);
 create_parametrized_test!(shortint_default_scalar_bitand);
 create_parametrized_test!(shortint_default_scalar_bitor);
 create_parametrized_test!(shortint_default_scalar_bitxor);
@@ -932,6 +936,84 @@ where
     }
 }
 
+/// test scalar bitwise 'and' with a u16 scalar with the LWE server key
+fn shortint_unchecked_scalar_bitand_u16<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u16>() as u64;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+
+        let ct_res = sks.unchecked_scalar_bitand_u16(&ctxt_0, clear_1 as u16);
+
+        let dec_res = cks.decrypt(&ct_res);
+
+        assert_eq!(clear_0 & clear_1, dec_res);
+
+        let ct_res = sks.unchecked_scalar_bitand_u16(&ctxt_0, 0x00FF);
+
+        let dec_res = cks.decrypt(&ct_res);
+
+        assert_eq!(clear_0 & 0x00FF, dec_res);
+
+        let mut ct_res = ctxt_0.clone();
+        sks.unchecked_scalar_bitand_u16_assign(&mut ct_res, clear_1 as u16);
+
+        let dec_res = cks.decrypt(&ct_res);
+
+        assert_eq!(clear_0 & clear_1, dec_res);
+    }
+}
+
+/// test scalar bitwise 'not' with the LWE server key
+fn shortint_unchecked_scalar_bitnot<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    for clear in 0..modulus {
+        let ctxt = cks.encrypt(clear);
+
+        let ct_res = sks.unchecked_scalar_bitnot(&ctxt);
+
+        let dec_res = cks.decrypt(&ct_res);
+
+        assert_eq!(!clear % modulus, dec_res);
+
+        let mut ct_res = sks.unchecked_scalar_bitnot(&ct_res);
+
+        let dec_res = cks.decrypt(&ct_res);
+
+        assert_eq!(clear, dec_res);
+
+        sks.unchecked_scalar_bitnot_assign(&mut ct_res);
+
+        let dec_res = cks.decrypt(&ct_res);
+
+        assert_eq!(!clear % modulus, dec_res);
+
+        sks.unchecked_scalar_bitnot_assign(&mut ct_res);
+
+        let dec_res = cks.decrypt(&ct_res);
+
+        assert_eq!(clear, dec_res);
+    }
+}
+
 /// test bitwise 'and' with the LWE server key
 fn shortint_smart_bitand<P>(param: P)
 where
@@ -1007,6 +1089,54 @@ where
     }
 }
 
+/// test chaining smart scalar bitwise operations on inputs with non empty carries
+fn shortint_smart_scalar_bitwise_chain<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+    let mod_scalar = cks.parameters.carry_modulus().0 as u8;
+
+    for _ in 0..NB_TEST_SMART {
+        let mut clear = rng.gen::<u64>() % modulus;
+
+        let mut ctxt = cks.encrypt(clear);
+
+        for i in 0..NB_SUB_TEST_SMART {
+            let scalar = rng.gen::<u8>() % mod_scalar;
+            let clear_1 = rng.gen::<u64>() % modulus;
+
+            // fill the carries, the smart operation gets no manual cleaning
+            sks.unchecked_scalar_mul_assign(&mut ctxt, scalar);
+            clear *= scalar as u64;
+
+            match i % 3 {
+                0 => {
+                    ctxt = sks.smart_scalar_bitand(&mut ctxt, clear_1 as u8);
+                    clear &= clear_1;
+                }
+                1 => {
+                    ctxt = sks.smart_scalar_bitor(&mut ctxt, clear_1 as u8);
+                    clear = (clear % modulus) | clear_1;
+                }
+                _ => {
+                    ctxt = sks.smart_scalar_bitxor(&mut ctxt, clear_1 as u8);
+                    clear = (clear % modulus) ^ clear_1;
+                }
+            }
+
+            let dec_res = cks.decrypt(&ctxt);
+
+            assert_eq!(clear, dec_res);
+        }
+    }
+}
+
 /// test default bitwise 'and' with the LWE server key
 fn shortint_default_bitand<P>(param: P)
 where
@@ -2922,6 +3052,58 @@ where
     }
 }
 
+/// test lookup table shifts for every shift amount up to the message bit-width and past it
+fn shortint_lookup_table_shift_clamped<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+    let message_bits = modulus.ilog2() as u8;
+    let full_bits = (modulus * cks.parameters.carry_modulus().0 as u64).ilog2() as u8;
+
+    for shift in (1..=message_bits).chain([u8::MAX]) {
+        // Shifting by the message bit-width or more always gives 0
+        let clamped_shift = shift.min(message_bits);
+
+        for _ in 0..NB_TEST {
+            let clear_0 = rng.gen::<u64>() % modulus;
+
+            // encryption of an integer
+            let mut ctxt_0 = cks.encrypt(clear_0);
+
+            let ct_res = sks.unchecked_scalar_right_shift(&ctxt_0, shift);
+            let dec_res = cks.decrypt(&ct_res);
+            assert_eq!(clear_0 >> clamped_shift, dec_res);
+
+            let ct_res = sks.scalar_right_shift(&ctxt_0, shift);
+            let dec_res = cks.decrypt(&ct_res);
+            assert_eq!(clear_0 >> clamped_shift, dec_res);
+
+            let ct_res = sks.scalar_left_shift(&ctxt_0, shift);
+            let dec_res = cks.decrypt(&ct_res);
+            assert_eq!((clear_0 << clamped_shift) % modulus, dec_res);
+
+            let fits_in_carry =
+                shift < full_bits && sks.is_scalar_left_shift_possible(&ctxt_0, shift).is_ok();
+
+            let ct_res = sks.smart_scalar_left_shift(&mut ctxt_0, shift);
+            let dec_res = cks.decrypt(&ct_res);
+            assert_eq!((clear_0 << clamped_shift) % modulus, dec_res);
+
+            // When the shift fits in the carry, the shifted out bits are kept there
+            if fits_in_carry {
+                let dec_res = cks.decrypt_message_and_carry(&ct_res);
+                assert_eq!(clear_0 << shift, dec_res);
+            }
+        }
+    }
+}
+
 /// test '<<' operation
 fn shortint_unchecked_left_shift<P>(param: P)
 where